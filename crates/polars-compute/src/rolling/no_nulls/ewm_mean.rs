@@ -0,0 +1,110 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::MutableBitmap;
+
+use super::*;
+
+/// Derive the smoothing factor `alpha` from one of the common exponential-decay
+/// specifications. Exactly one of `com`, `span`, `half_life` or `alpha` should be `Some`.
+pub fn alpha_from_params(
+    com: Option<f64>,
+    span: Option<f64>,
+    half_life: Option<f64>,
+    alpha: Option<f64>,
+) -> f64 {
+    if let Some(alpha) = alpha {
+        alpha
+    } else if let Some(com) = com {
+        1.0 / (1.0 + com)
+    } else if let Some(span) = span {
+        2.0 / (span + 1.0)
+    } else if let Some(half_life) = half_life {
+        1.0 - (-std::f64::consts::LN_2 / half_life).exp()
+    } else {
+        panic!("one of `com`, `span`, `half_life` or `alpha` must be set")
+    }
+}
+
+fn ewm_window<T>(window: &[T], alpha: T, adjust: bool) -> T
+where
+    T: NativeType + Float + AddAssign,
+{
+    let one_sub_alpha = T::one() - alpha;
+    if adjust {
+        let mut num = T::zero();
+        let mut denom = T::zero();
+        let mut weight = T::one();
+        for &x in window.iter().rev() {
+            num += weight * x;
+            denom += weight;
+            weight = weight * one_sub_alpha;
+        }
+        num / denom
+    } else {
+        let mut iter = window.iter();
+        let mut y = *iter.next().unwrap();
+        for &x in iter {
+            y = alpha * x + one_sub_alpha * y;
+        }
+        y
+    }
+}
+
+/// Rolling exponentially-weighted mean.
+///
+/// Unlike [`rolling_mean`], the window is not aggregated incrementally: each output is the
+/// result of applying the EWM recurrence `y_t = alpha * x_t + (1 - alpha) * y_{t-1}` to the
+/// values currently in the window, seeded on the oldest value in that window. When `adjust` is
+/// set, the normalized form `y_t = (sum_i (1-alpha)^i x_{t-i}) / (sum_i (1-alpha)^i)` is used
+/// instead.
+pub fn rolling_ewm_mean<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    alpha: f64,
+    adjust: bool,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + AddAssign,
+{
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    let alpha = T::from(alpha).unwrap();
+    let len = values.len();
+    let mut out = Vec::with_capacity(len);
+    let mut validity = MutableBitmap::with_capacity(len);
+    for idx in 0..len {
+        let (start, end) = offset_fn(idx, window_size, len);
+        if end - start < min_periods {
+            out.push(T::default());
+            validity.push(false);
+        } else {
+            out.push(ewm_window(&values[start..end], alpha, adjust));
+            validity.push(true);
+        }
+    }
+    Ok(PrimitiveArray::new(T::PRIMITIVE.into(), out.into(), Some(validity.into())).to_boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Regression test for a sign error: `alpha_from_params` previously computed
+    // `1.0 - (half_life / LN_2).exp()`, which is negative for every positive `half_life` (alpha
+    // must be in `(0, 1]`).
+    #[test]
+    fn alpha_from_half_life_is_in_unit_range() {
+        let alpha = alpha_from_params(None, None, Some(1.0), None);
+        assert!((0.0..=1.0).contains(&alpha));
+        assert!((alpha - 0.5).abs() < 1e-12);
+
+        let alpha = alpha_from_params(None, None, Some(2.0), None);
+        assert!((0.0..=1.0).contains(&alpha));
+        assert!((alpha - (1.0 - 2.0f64.powf(-0.5))).abs() < 1e-12);
+    }
+}
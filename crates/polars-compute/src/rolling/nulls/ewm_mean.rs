@@ -0,0 +1,155 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::MutableBitmap;
+
+use super::*;
+
+fn ewm_window<T>(
+    window: &[T],
+    window_validity: &[bool],
+    alpha: T,
+    adjust: bool,
+    ignore_nulls: bool,
+) -> Option<T>
+where
+    T: NativeType + Float + AddAssign,
+{
+    let one_sub_alpha = T::one() - alpha;
+    let valid = window
+        .iter()
+        .zip(window_validity.iter())
+        .filter_map(|(&x, &v)| v.then_some(x));
+
+    if adjust {
+        let mut num = T::zero();
+        let mut denom = T::zero();
+        let mut weight = T::one();
+        let mut any = false;
+        // Walk from newest to oldest so decay is anchored on the window's right edge, skipping
+        // nulls without advancing the decay when `ignore_nulls` is set.
+        for (&x, &v) in window.iter().zip(window_validity.iter()).rev() {
+            if !v {
+                if ignore_nulls {
+                    continue;
+                }
+                weight = weight * one_sub_alpha;
+                continue;
+            }
+            any = true;
+            num += weight * x;
+            denom += weight;
+            weight = weight * one_sub_alpha;
+        }
+        any.then(|| num / denom)
+    } else if ignore_nulls {
+        let mut iter = valid;
+        let mut y = iter.next()?;
+        for x in iter {
+            y = alpha * x + one_sub_alpha * y;
+        }
+        Some(y)
+    } else {
+        // Unlike `ignore_nulls`, a null here must still advance the decay: the recurrence is
+        // seeded on the first valid value, and each subsequent valid value is combined with the
+        // previous one through one extra `one_sub_alpha` factor per null skipped since then.
+        let mut y = None;
+        let mut null_gap = 0i32;
+        for (&x, &v) in window.iter().zip(window_validity.iter()) {
+            if !v {
+                if y.is_some() {
+                    null_gap += 1;
+                }
+                continue;
+            }
+            y = Some(match y {
+                None => x,
+                Some(prev) => alpha * x + one_sub_alpha.powi(null_gap + 1) * prev,
+            });
+            null_gap = 0;
+        }
+        y
+    }
+}
+
+/// Null-aware rolling exponentially-weighted mean, see
+/// [`super::super::no_nulls::ewm_mean::rolling_ewm_mean`] for the recurrence this implements.
+/// `ignore_nulls` controls whether a null inside the window still advances the decay applied to
+/// the next valid value (`false`, the default EWM semantics) or is skipped over as if it were
+/// never there (`true`).
+pub fn rolling_ewm_mean<T>(
+    values: &[T],
+    validity: &Bitmap,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    alpha: f64,
+    adjust: bool,
+    ignore_nulls: bool,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + AddAssign,
+{
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    let alpha = T::from(alpha).unwrap();
+    let len = values.len();
+    let mut out = Vec::with_capacity(len);
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    for idx in 0..len {
+        let (start, end) = offset_fn(idx, window_size, len);
+        let window = &values[start..end];
+        let window_validity: Vec<bool> = (start..end).map(|i| validity.get_bit(i)).collect();
+        let valid_count = window_validity.iter().filter(|v| **v).count();
+
+        if valid_count < min_periods {
+            out.push(T::default());
+            out_validity.push(false);
+            continue;
+        }
+        match ewm_window(window, &window_validity, alpha, adjust, ignore_nulls) {
+            Some(value) => {
+                out.push(value);
+                out_validity.push(true);
+            },
+            None => {
+                out.push(T::default());
+                out_validity.push(false);
+            },
+        }
+    }
+    Ok(PrimitiveArray::new(T::PRIMITIVE.into(), out.into(), Some(out_validity.into())).to_boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Regression test: with `ignore_nulls = false`, a null inside the window must still advance
+    // the decay applied to the next valid value -- skipping it for free (as `ignore_nulls = true`
+    // does) understates how stale that next value's predecessor actually is.
+    #[test]
+    fn null_gap_advances_decay_when_not_ignoring_nulls() {
+        let alpha = 0.5f64;
+        let one_sub_alpha = 1.0 - alpha;
+
+        // [10.0, null, null, 20.0]: two nulls between the seed and the second valid value, so the
+        // seed's weight should be discounted by `one_sub_alpha.powi(3)` (one null_gap + 1), not
+        // `one_sub_alpha.powi(1)` as it would be if nulls were skipped for free.
+        let window = [10.0f64, 0.0, 0.0, 20.0];
+        let window_validity = [true, false, false, true];
+
+        let got = ewm_window(&window, &window_validity, alpha, false, false).unwrap();
+        let expected = alpha * 20.0 + one_sub_alpha.powi(3) * 10.0;
+        assert!((got - expected).abs() < 1e-12);
+
+        // With `ignore_nulls = true` the same window collapses to a plain two-point recurrence
+        // with no extra decay from the skipped nulls.
+        let got_ignoring = ewm_window(&window, &window_validity, alpha, false, true).unwrap();
+        let expected_ignoring = alpha * 20.0 + one_sub_alpha * 10.0;
+        assert!((got_ignoring - expected_ignoring).abs() < 1e-12);
+        assert_ne!(got, got_ignoring);
+    }
+}
@@ -0,0 +1,170 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::MutableBitmap;
+use polars_error::polars_ensure;
+
+use super::*;
+
+pub struct MeanWindow<'a, T> {
+    sum: SumWindow<'a, T, f64>,
+}
+
+impl<'a, T> RollingAggWindowNulls<'a, T> for MeanWindow<'a, T>
+where
+    T: NativeType
+        + IsFloat
+        + std::iter::Sum
+        + AddAssign
+        + SubAssign
+        + Div<Output = T>
+        + NumCast
+        + Add<Output = T>
+        + Sub<Output = T>
+        + PartialOrd,
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        Self {
+            sum: SumWindow::new(slice, validity, start, end, params, window_size),
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let sum = self.sum.update(start, end)?;
+        let valid_count = (end - start) - self.sum.null_count;
+        if valid_count == 0 {
+            return None;
+        }
+        Some(sum / NumCast::from(valid_count).unwrap())
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        let valid_count = (self.sum.last_end - self.sum.last_start) - self.sum.null_count;
+        valid_count >= min_periods
+    }
+}
+
+pub fn rolling_mean<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    validity: &Bitmap,
+    _params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    match weights {
+        None => rolling_apply_agg_window::<MeanWindow<_>, _, _>(
+            values,
+            validity,
+            window_size,
+            min_periods,
+            offset_fn,
+            None,
+        ),
+        Some(weights) => {
+            let wts = no_nulls::coerce_weights(weights);
+            let wsum = wts.iter().fold(T::zero(), |acc, x| acc + *x);
+            polars_ensure!(
+                wsum != T::zero(),
+                ComputeError: "Weighted mean is undefined if weights sum to 0"
+            );
+            rolling_weighted_mean(values, validity, window_size, min_periods, offset_fn, &wts)
+        },
+    }
+}
+
+/// A weighted mean is a weighted sum with normalized weights. Unlike the no-nulls version, the
+/// weights can't be normalized once globally: a window missing some of its values must
+/// renormalize the surviving (non-null) weights so they sum to 1, rather than dividing by the
+/// weight total of a full window it doesn't actually have.
+fn rolling_weighted_mean<T>(
+    values: &[T],
+    validity: &Bitmap,
+    window_size: usize,
+    min_periods: usize,
+    offset_fn: fn(usize, usize, usize) -> (usize, usize),
+    weights: &[T],
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    let len = values.len();
+    let mut out = Vec::with_capacity(len);
+    let mut out_validity = MutableBitmap::with_capacity(len);
+    for idx in 0..len {
+        let (start, end) = offset_fn(idx, window_size, len);
+        // Weights are anchored on the window's right edge, so a truncated edge window uses the
+        // trailing `end - start` weights.
+        let weights = &weights[weights.len() - (end - start)..];
+
+        let mut weighted_sum = T::zero();
+        let mut weight_sum = T::zero();
+        let mut valid_count = 0usize;
+        for (i, &w) in (start..end).zip(weights) {
+            if validity.get_bit(i) {
+                weighted_sum += w * values[i];
+                weight_sum += w;
+                valid_count += 1;
+            }
+        }
+
+        if valid_count < min_periods || weight_sum == T::zero() {
+            out.push(T::default());
+            out_validity.push(false);
+        } else {
+            out.push(weighted_sum / weight_sum);
+            out_validity.push(true);
+        }
+    }
+    Ok(PrimitiveArray::new(T::PRIMITIVE.into(), out.into(), Some(out_validity.into())).to_boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+
+    use super::*;
+
+    // Regression test for the bug where a window missing some values divided its weighted sum by
+    // the full window's weight total instead of just the surviving (non-null) weights' total.
+    #[test]
+    fn weighted_mean_renormalizes_per_window() {
+        let values = [1.0f64, 2.0, 3.0, 4.0];
+        let validity = Bitmap::from_iter([true, false, true, true]);
+        let weights = [1.0f64, 2.0, 3.0];
+
+        let out = rolling_mean(
+            &values,
+            3,
+            1,
+            false,
+            Some(&weights),
+            &validity,
+            None,
+        )
+        .unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+
+        // idx 0: window [1.0], right-anchored weight [3.0] -> mean 1.0
+        // idx 1: window [1.0, null], surviving weight [2.0] -> mean 1.0 (not 1.0*2/6)
+        // idx 2: window [1.0, null, 3.0], surviving weights [1.0, 3.0] renormalized -> 10.0 / 4.0
+        // idx 3: window [null, 3.0, 4.0], surviving weights [2.0, 3.0] renormalized -> 18.0 / 5.0
+        assert_eq!(out.values().as_slice(), &[1.0, 1.0, 2.5, 3.6]);
+        assert!(out.validity().is_none() || out.validity().unwrap().get_bit(0));
+    }
+}
@@ -0,0 +1,83 @@
+//! Backs `ArrayNameSpace::eval` (see `polars-plan/src/dsl/array.rs`): evaluate a sub-expression
+//! against each row's array/list elements independently and collect the per-row results back
+//! into one output row each.
+//!
+//! Within the sub-expression, [`element`] refers to the current row's elements. This module
+//! implements that by exploding every row's elements into one flat `DataFrame` tagged with the
+//! originating row index, evaluating the expression once across the whole column (wrapping it in
+//! `.over(row_id)` so any aggregating part of the expression stays scoped to its own row instead
+//! of reducing across the whole input), and slicing the single flat result back into per-row
+//! outputs -- one lazy-engine invocation total, not one per row.
+
+use polars_core::prelude::*;
+use polars_error::polars_bail;
+use polars_lazy::prelude::*;
+
+const ELEMENT_COLUMN: &str = "element";
+const ROW_ID_COLUMN: &str = "row_id";
+
+/// A reference to the current array/list's elements inside an `eval` sub-expression.
+pub fn element() -> Expr {
+    col(ELEMENT_COLUMN)
+}
+
+/// Evaluate `expr` against each row of `s` (an `Array`- or `List`-typed column), returning a
+/// `List` column of the per-row results. A null row stays null; it is never substituted with an
+/// empty list.
+///
+/// `parallel` is accepted for interface parity with the `List` namespace's `eval`; this
+/// implementation already evaluates every row in a single batched pass, so there's no per-row
+/// work left to parallelize.
+pub fn array_eval(s: &Series, expr: &Expr, _parallel: bool) -> PolarsResult<Series> {
+    let len = s.len();
+    let mut element_values: Vec<AnyValue<'static>> = Vec::new();
+    let mut row_ids: Vec<IdxSize> = Vec::new();
+    let mut row_is_null = vec![false; len];
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0usize);
+
+    for idx in 0..len {
+        match s.get(idx)? {
+            AnyValue::Null => row_is_null[idx] = true,
+            AnyValue::List(row) | AnyValue::Array(row, _) => {
+                for i in 0..row.len() {
+                    element_values.push(row.get(i)?.into_static());
+                    row_ids.push(idx as IdxSize);
+                }
+            },
+            other => polars_bail!(
+                ComputeError: "eval expects an Array/List-typed column, got {other:?}"
+            ),
+        }
+        offsets.push(element_values.len());
+    }
+
+    let evaluated = if element_values.is_empty() {
+        Series::new_null(PlSmallStr::from_static(ELEMENT_COLUMN), 0)
+    } else {
+        let element = Series::from_any_values(
+            PlSmallStr::from_static(ELEMENT_COLUMN),
+            &element_values,
+            false,
+        )?;
+        let row_id = Series::new(PlSmallStr::from_static(ROW_ID_COLUMN), row_ids);
+        let df = DataFrame::new(vec![row_id.into(), element.into()])?;
+        df.lazy()
+            .select([expr.clone().over([col(ROW_ID_COLUMN)]).alias(ELEMENT_COLUMN)])
+            .collect()?
+            .column(ELEMENT_COLUMN)?
+            .as_materialized_series()
+            .clone()
+    };
+
+    let mut rows: Vec<Option<Series>> = Vec::with_capacity(len);
+    for idx in 0..len {
+        if row_is_null[idx] {
+            rows.push(None);
+            continue;
+        }
+        let (start, end) = (offsets[idx], offsets[idx + 1]);
+        rows.push(Some(evaluated.slice(start as i64, end - start)));
+    }
+    Ok(Series::new(s.name().clone(), rows))
+}
@@ -0,0 +1,97 @@
+//! Row-wise set operations between two `Array`/`List` columns, backing
+//! `ArrayNameSpace::set_operation` (see `polars-plan/src/dsl/array.rs`).
+//!
+//! The result width can vary per row once duplicates collapse or elements are removed, so the
+//! output is always a `List` column even when both inputs are fixed-width `Array`s.
+
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure};
+
+/// Which set operation [`array_set_operation`] should apply between corresponding rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetOperation {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// Apply `op` row-wise between `lhs` and `rhs`, which must be `Array`- or `List`-typed columns of
+/// equal length (their inner dtypes are not otherwise checked here; a mismatch surfaces as a
+/// `Series::from_any_values` error on the first offending row).
+pub fn array_set_operation(lhs: &Series, rhs: &Series, op: SetOperation) -> PolarsResult<Series> {
+    polars_ensure!(
+        lhs.len() == rhs.len(),
+        ComputeError:
+        "set_operation requires both columns to have the same length, got {} and {}",
+        lhs.len(),
+        rhs.len()
+    );
+
+    let mut rows: Vec<Option<Series>> = Vec::with_capacity(lhs.len());
+    for idx in 0..lhs.len() {
+        let row = match (lhs.get(idx)?, rhs.get(idx)?) {
+            (AnyValue::Null, _) | (_, AnyValue::Null) => None,
+            (left, right) => {
+                let left = row_values(left)?;
+                let right = row_values(right)?;
+                let combined = apply_set_operation(op, &left, &right);
+                Some(Series::from_any_values(PlSmallStr::EMPTY, &combined, false)?)
+            },
+        };
+        rows.push(row);
+    }
+    Ok(Series::new(lhs.name().clone(), rows))
+}
+
+fn row_values(value: AnyValue) -> PolarsResult<Vec<AnyValue<'static>>> {
+    let series = match value {
+        AnyValue::List(series) => series,
+        AnyValue::Array(series, _) => series,
+        other => polars_bail!(
+            ComputeError: "set_operation expects an Array/List-typed column, got {other:?}"
+        ),
+    };
+    (0..series.len())
+        .map(|i| series.get(i).map(|v| v.into_static()))
+        .collect()
+}
+
+fn apply_set_operation(
+    op: SetOperation,
+    left: &[AnyValue<'static>],
+    right: &[AnyValue<'static>],
+) -> Vec<AnyValue<'static>> {
+    let mut out = match op {
+        SetOperation::Union => {
+            let mut out = left.to_vec();
+            out.extend(right.iter().cloned());
+            out
+        },
+        SetOperation::Intersection => left
+            .iter()
+            .filter(|v| right.contains(v))
+            .cloned()
+            .collect(),
+        SetOperation::Difference => left
+            .iter()
+            .filter(|v| !right.contains(v))
+            .cloned()
+            .collect(),
+        SetOperation::SymmetricDifference => {
+            let mut out: Vec<_> = left.iter().filter(|v| !right.contains(v)).cloned().collect();
+            out.extend(right.iter().filter(|v| !left.contains(v)).cloned());
+            out
+        },
+    };
+
+    // Union and symmetric-difference can still contain duplicates pulled in from either side;
+    // collapse them while preserving first-seen order.
+    let mut deduped: Vec<AnyValue<'static>> = Vec::with_capacity(out.len());
+    for value in out.drain(..) {
+        if !deduped.contains(&value) {
+            deduped.push(value);
+        }
+    }
+    deduped
+}
@@ -0,0 +1,4 @@
+pub mod array_eval;
+pub mod array_set_operation;
+
+pub use array_set_operation::{SetOperation, array_set_operation};
@@ -0,0 +1,343 @@
+//! Decoding for Parquet's `DELTA_BINARY_PACKED`, `DELTA_LENGTH_BYTE_ARRAY` and `DELTA_BYTE_ARRAY`
+//! encodings.
+//!
+//! `DELTA_BINARY_PACKED` stores a header of `(block size in values, miniblocks per block, total
+//! value count, first value)` followed by blocks of `(min delta, one bit-width byte per
+//! miniblock, the miniblocks' bit-packed deltas)`. Every decoded value is the running sum of
+//! `first_value` and each subsequent `min_delta + bit_unpacked_delta`.
+//!
+//! `DELTA_LENGTH_BYTE_ARRAY` is a `DELTA_BINARY_PACKED`-encoded stream of lengths followed by the
+//! concatenated raw value bytes. `DELTA_BYTE_ARRAY` additionally delta-encodes the values
+//! themselves against their predecessor: a `DELTA_BINARY_PACKED` stream of shared-prefix
+//! lengths, a `DELTA_BINARY_PACKED` stream of suffix lengths, then the concatenated suffix bytes.
+//!
+//! Unwired: `primitive::IntDecoder`/`FloatDecoder` and `binview::BinViewDecoder` -- the page
+//! decoders that own the `Encoding::DeltaBinaryPacked`/`DeltaLengthByteArray`/`DeltaByteArray`
+//! match arms -- aren't part of this checkout, so nothing here dispatches to
+//! `DeltaBinaryPackedDecoder`/`decode_delta_length_byte_array`/`decode_delta_byte_array` yet. A
+//! page actually encoded with one of these three encodings still can't be read end-to-end; this
+//! module only supplies the decoding logic those dispatch arms would call.
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+/// A cursor over a `DELTA_BINARY_PACKED`-encoded byte slice.
+pub struct DeltaBinaryPackedDecoder<'a> {
+    values_remaining: usize,
+    block_size: usize,
+    miniblocks_per_block: usize,
+    values_per_miniblock: usize,
+    last_value: i64,
+    // Buffered values from the miniblock currently being unpacked.
+    buffer: Vec<i64>,
+    buffer_pos: usize,
+    data: &'a [u8],
+    pos: usize,
+}
+
+fn read_uleb128(data: &[u8], pos: &mut usize) -> ParquetResult<u64> {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *data
+            .get(*pos)
+            .ok_or_else(|| ParquetError::oos("truncated DELTA_BINARY_PACKED header"))?;
+        *pos += 1;
+        result |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(result)
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn read_zigzag_varint(data: &[u8], pos: &mut usize) -> ParquetResult<i64> {
+    Ok(zigzag_decode(read_uleb128(data, pos)?))
+}
+
+impl<'a> DeltaBinaryPackedDecoder<'a> {
+    pub fn try_new(data: &'a [u8]) -> ParquetResult<Self> {
+        let mut pos = 0;
+        let block_size = read_uleb128(data, &mut pos)? as usize;
+        let miniblocks_per_block = read_uleb128(data, &mut pos)? as usize;
+        let total_value_count = read_uleb128(data, &mut pos)? as usize;
+        let first_value = read_zigzag_varint(data, &mut pos)?;
+
+        polars_ensure_divisible(block_size, miniblocks_per_block)?;
+
+        Ok(Self {
+            // `first_value` has already been produced; the running decoder state still needs
+            // to emit it as the very first value.
+            values_remaining: total_value_count,
+            block_size,
+            miniblocks_per_block,
+            values_per_miniblock: block_size / miniblocks_per_block.max(1),
+            last_value: first_value,
+            buffer: vec![first_value],
+            buffer_pos: 0,
+            data,
+            pos,
+        })
+    }
+
+    fn decode_block(&mut self) -> ParquetResult<()> {
+        let min_delta = read_zigzag_varint(self.data, &mut self.pos)?;
+        let bit_widths = self
+            .data
+            .get(self.pos..self.pos + self.miniblocks_per_block)
+            .ok_or_else(|| ParquetError::oos("truncated DELTA_BINARY_PACKED block header"))?
+            .to_vec();
+        self.pos += self.miniblocks_per_block;
+
+        self.buffer.clear();
+        self.buffer_pos = 0;
+
+        let mut remaining = self.values_remaining;
+        for bit_width in bit_widths {
+            // Every miniblock occupies its full `values_per_miniblock * bit_width` bits on disk
+            // regardless of how many of those values are still needed — the spec pads the last,
+            // partially-used miniblock out to the same physical size — so `pos` must always
+            // advance past all of it, even once `remaining` has dropped to (or below) zero.
+            let deltas =
+                unpack_miniblock(self.data, &mut self.pos, bit_width, self.values_per_miniblock)?;
+            let n = remaining.min(self.values_per_miniblock);
+            for delta in deltas.into_iter().take(n) {
+                self.last_value += min_delta + delta as i64;
+                self.buffer.push(self.last_value);
+            }
+            remaining -= n;
+        }
+        Ok(())
+    }
+
+    /// Decode the next `n` values into `out`, failing if the stream holds fewer than `n`.
+    pub fn collect_n(&mut self, out: &mut Vec<i64>, n: usize) -> ParquetResult<()> {
+        for _ in 0..n {
+            if self.buffer_pos >= self.buffer.len() {
+                self.decode_block()?;
+            }
+            let Some(&value) = self.buffer.get(self.buffer_pos) else {
+                return Err(ParquetError::oos(
+                    "DELTA_BINARY_PACKED stream exhausted before requested value count",
+                ));
+            };
+            self.buffer_pos += 1;
+            self.values_remaining = self.values_remaining.saturating_sub(1);
+            out.push(value);
+        }
+        Ok(())
+    }
+}
+
+fn polars_ensure_divisible(block_size: usize, miniblocks_per_block: usize) -> ParquetResult<()> {
+    if miniblocks_per_block == 0 || block_size % miniblocks_per_block != 0 {
+        return Err(ParquetError::oos(
+            "DELTA_BINARY_PACKED block size must be a multiple of the miniblock count",
+        ));
+    }
+    Ok(())
+}
+
+/// Unpack `n` `bit_width`-wide unsigned deltas, advancing `pos` by exactly
+/// `n * bit_width` bits rounded up to a byte. Callers always pass the full
+/// `values_per_miniblock` as `n` (even for a block's last, partially-used miniblock) since the
+/// format pads every miniblock out to its full physical size regardless of how many of its
+/// values are actually needed.
+fn unpack_miniblock(
+    data: &[u8],
+    pos: &mut usize,
+    bit_width: u8,
+    n: usize,
+) -> ParquetResult<Vec<u64>> {
+    let bit_width = bit_width as usize;
+    let mut out = Vec::with_capacity(n);
+    let mut bit_pos = 0usize;
+    for _ in 0..n {
+        let mut value = 0u64;
+        for b in 0..bit_width {
+            let global_bit = bit_pos + b;
+            let byte = *data
+                .get(*pos + global_bit / 8)
+                .ok_or_else(|| ParquetError::oos("truncated DELTA_BINARY_PACKED miniblock"))?;
+            let bit = (byte >> (global_bit % 8)) & 1;
+            value |= (bit as u64) << b;
+        }
+        out.push(value);
+        bit_pos += bit_width;
+    }
+    *pos += bit_pos.div_ceil(8);
+    Ok(out)
+}
+
+/// Decode a `DELTA_LENGTH_BYTE_ARRAY`-encoded buffer into its `num_values` raw byte strings.
+pub fn decode_delta_length_byte_array(data: &[u8], num_values: usize) -> ParquetResult<Vec<Vec<u8>>> {
+    let mut decoder = DeltaBinaryPackedDecoder::try_new(data)?;
+    let mut lengths = Vec::with_capacity(num_values);
+    decoder.collect_n(&mut lengths, num_values)?;
+
+    let mut pos = decoder.pos;
+    let mut values = Vec::with_capacity(num_values);
+    for len in lengths {
+        let len = len as usize;
+        let bytes = data
+            .get(pos..pos + len)
+            .ok_or_else(|| ParquetError::oos("truncated DELTA_LENGTH_BYTE_ARRAY value"))?;
+        values.push(bytes.to_vec());
+        pos += len;
+    }
+    Ok(values)
+}
+
+/// Decode a `DELTA_BYTE_ARRAY`-encoded buffer into its `num_values` raw byte strings, each
+/// reconstructed as `previous_value[..prefix_length] + suffix`.
+pub fn decode_delta_byte_array(data: &[u8], num_values: usize) -> ParquetResult<Vec<Vec<u8>>> {
+    let mut prefix_decoder = DeltaBinaryPackedDecoder::try_new(data)?;
+    let mut prefix_lengths = Vec::with_capacity(num_values);
+    prefix_decoder.collect_n(&mut prefix_lengths, num_values)?;
+
+    let mut suffix_decoder = DeltaBinaryPackedDecoder::try_new(&data[prefix_decoder.pos..])?;
+    let mut suffix_lengths = Vec::with_capacity(num_values);
+    suffix_decoder.collect_n(&mut suffix_lengths, num_values)?;
+
+    let mut pos = prefix_decoder.pos + suffix_decoder.pos;
+    let mut values: Vec<Vec<u8>> = Vec::with_capacity(num_values);
+    let mut previous: Vec<u8> = Vec::new();
+    for (prefix_len, suffix_len) in prefix_lengths.into_iter().zip(suffix_lengths) {
+        let prefix_len = prefix_len as usize;
+        let suffix_len = suffix_len as usize;
+        let suffix = data
+            .get(pos..pos + suffix_len)
+            .ok_or_else(|| ParquetError::oos("truncated DELTA_BYTE_ARRAY value"))?;
+
+        let mut value = Vec::with_capacity(prefix_len + suffix_len);
+        value.extend_from_slice(&previous[..prefix_len]);
+        value.extend_from_slice(suffix);
+
+        pos += suffix_len;
+        previous = value.clone();
+        values.push(value);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A minimal test-only mirror of the production reader, used to build inputs that exercise
+    // edge cases (in particular, a final miniblock that is only partially used).
+    fn write_uleb128(out: &mut Vec<u8>, mut value: u64) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value != 0 {
+                out.push(byte | 0x80);
+            } else {
+                out.push(byte);
+                break;
+            }
+        }
+    }
+
+    fn write_zigzag(out: &mut Vec<u8>, value: i64) {
+        let encoded = ((value << 1) ^ (value >> 63)) as u64;
+        write_uleb128(out, encoded);
+    }
+
+    fn bit_width_for(values: &[u64]) -> u32 {
+        64 - values.iter().copied().max().unwrap_or(0).leading_zeros()
+    }
+
+    fn pack_miniblock(out: &mut Vec<u8>, values: &[u64], bit_width: u32) {
+        if bit_width == 0 {
+            return;
+        }
+        let mut bytes = vec![0u8; (values.len() * bit_width as usize).div_ceil(8)];
+        let mut bit_pos = 0usize;
+        for &value in values {
+            for b in 0..bit_width {
+                if (value >> b) & 1 == 1 {
+                    bytes[bit_pos / 8] |= 1 << (bit_pos % 8);
+                }
+                bit_pos += 1;
+            }
+        }
+        out.extend(bytes);
+    }
+
+    /// Encode `values` as `DELTA_BINARY_PACKED`, padding the last miniblock of the last block out
+    /// to `values_per_miniblock` the way real writers do.
+    fn encode_delta_binary_packed(values: &[i64], block_size: usize, miniblocks_per_block: usize) -> Vec<u8> {
+        let values_per_miniblock = block_size / miniblocks_per_block;
+        let mut out = Vec::new();
+        write_uleb128(&mut out, block_size as u64);
+        write_uleb128(&mut out, miniblocks_per_block as u64);
+        write_uleb128(&mut out, values.len() as u64);
+        write_zigzag(&mut out, values[0]);
+
+        let deltas: Vec<i64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+        let mut idx = 0;
+        while idx < deltas.len() {
+            let block_end = (idx + block_size).min(deltas.len());
+            let block = &deltas[idx..block_end];
+            let min_delta = *block.iter().min().unwrap();
+            write_zigzag(&mut out, min_delta);
+
+            let miniblocks: Vec<Vec<u64>> = (0..miniblocks_per_block)
+                .map(|m| {
+                    (0..values_per_miniblock)
+                        .map(|k| {
+                            block
+                                .get(m * values_per_miniblock + k)
+                                .map_or(0, |delta| (delta - min_delta) as u64)
+                        })
+                        .collect()
+                })
+                .collect();
+            let bit_widths: Vec<u32> = miniblocks.iter().map(|m| bit_width_for(m)).collect();
+            out.extend(bit_widths.iter().map(|&b| b as u8));
+            for (values, bit_width) in miniblocks.iter().zip(&bit_widths) {
+                pack_miniblock(&mut out, values, *bit_width);
+            }
+            idx = block_end;
+        }
+        out
+    }
+
+    #[test]
+    fn decode_block_roundtrips_with_partially_used_final_miniblock() {
+        // 6 values -> 5 deltas in a block of 2 miniblocks of 4: the second miniblock only uses
+        // 1 of its 4 slots but is still physically padded to 4.
+        let values = vec![10i64, 12, 11, 11, 20, 5];
+        let encoded = encode_delta_binary_packed(&values, 8, 2);
+
+        let mut decoder = DeltaBinaryPackedDecoder::try_new(&encoded).unwrap();
+        let mut out = Vec::new();
+        decoder.collect_n(&mut out, values.len()).unwrap();
+        assert_eq!(out, values);
+    }
+
+    #[test]
+    fn delta_length_byte_array_survives_padded_miniblock() {
+        // Before the `pos`-advancement fix, the trailing padding bits of the partially-used
+        // final miniblock were never skipped, so the value bytes below were read starting at
+        // the wrong offset.
+        let lengths = vec![3i64, 1, 4, 1, 5, 9];
+        let mut data = encode_delta_binary_packed(&lengths, 8, 2);
+        let values: Vec<Vec<u8>> = lengths
+            .iter()
+            .enumerate()
+            .map(|(i, &len)| vec![i as u8; len as usize])
+            .collect();
+        for value in &values {
+            data.extend_from_slice(value);
+        }
+
+        let decoded = decode_delta_length_byte_array(&data, lengths.len()).unwrap();
+        assert_eq!(decoded, values);
+    }
+}
@@ -0,0 +1,625 @@
+//! Page-level predicate pushdown using Parquet's `ColumnIndex`/`OffsetIndex`.
+//!
+//! Two Thrift structures travel alongside a column chunk once a file is written with Parquet's
+//! "page index" feature: the `ColumnIndex`, which carries each page's
+//! `min_values`/`max_values`/`null_counts` (plus a `boundary_order` hint), and the
+//! `OffsetIndex`, which carries each page's `first_row_index`. This module decodes both, turns a
+//! [`StatisticsPredicate`] over the (truncated) min/max values into the set of rows still worth
+//! decoding, and hands the result back as the same [`Filter`] that
+//! [`super::simple::page_iter_to_array`] already threads through every decoder.
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::i256;
+use ethnum::I256;
+
+use super::super::{convert_i128, convert_i256};
+use super::utils::filter::Filter;
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::schema::types::{PhysicalType, PrimitiveLogicalType};
+use crate::parquet::types::int96_to_i64_ns;
+
+/// A tiny reader for the subset of Thrift's compact protocol used by `ColumnIndex`/
+/// `OffsetIndex`/`PageLocation` (structs, lists, bools, i32/i64 zigzag varints, binary).
+mod thrift_compact {
+    use crate::parquet::error::{ParquetError, ParquetResult};
+
+    pub struct Reader<'a> {
+        data: &'a [u8],
+        pub pos: usize,
+    }
+
+    impl<'a> Reader<'a> {
+        pub fn new(data: &'a [u8]) -> Self {
+            Self { data, pos: 0 }
+        }
+
+        fn next_byte(&mut self) -> ParquetResult<u8> {
+            let b = *self
+                .data
+                .get(self.pos)
+                .ok_or_else(|| ParquetError::oos("truncated thrift compact protocol struct"))?;
+            self.pos += 1;
+            Ok(b)
+        }
+
+        fn varint(&mut self) -> ParquetResult<u64> {
+            let mut result = 0u64;
+            let mut shift = 0;
+            loop {
+                let b = self.next_byte()?;
+                result |= ((b & 0x7F) as u64) << shift;
+                if b & 0x80 == 0 {
+                    break;
+                }
+                shift += 7;
+            }
+            Ok(result)
+        }
+
+        fn zigzag(&mut self) -> ParquetResult<i64> {
+            let v = self.varint()?;
+            Ok(((v >> 1) as i64) ^ -((v & 1) as i64))
+        }
+
+        pub fn i32(&mut self) -> ParquetResult<i32> {
+            Ok(self.zigzag()? as i32)
+        }
+
+        pub fn i64(&mut self) -> ParquetResult<i64> {
+            self.zigzag()
+        }
+
+        pub fn binary(&mut self) -> ParquetResult<Vec<u8>> {
+            let len = self.varint()? as usize;
+            let bytes = self
+                .data
+                .get(self.pos..self.pos + len)
+                .ok_or_else(|| ParquetError::oos("truncated thrift binary field"))?;
+            self.pos += len;
+            Ok(bytes.to_vec())
+        }
+
+        /// Reads the next field header, returning its Thrift compact-protocol type code, or
+        /// `None` at the struct's stop field (`0x00`).
+        pub fn field_header(&mut self, last_field_id: &mut i16) -> ParquetResult<Option<u8>> {
+            let header = self.next_byte()?;
+            if header == 0 {
+                return Ok(None);
+            }
+            let type_code = header & 0x0F;
+            let delta = (header >> 4) & 0x0F;
+            *last_field_id = if delta == 0 {
+                self.zigzag()? as i16
+            } else {
+                *last_field_id + delta as i16
+            };
+            Ok(Some(type_code))
+        }
+
+        /// Reads a list header, returning `(element_type, size)`.
+        pub fn list_header(&mut self) -> ParquetResult<(u8, usize)> {
+            let header = self.next_byte()?;
+            let element_type = header & 0x0F;
+            let size = (header >> 4) & 0x0F;
+            let size = if size == 0x0F {
+                self.varint()? as usize
+            } else {
+                size as usize
+            };
+            Ok((element_type, size))
+        }
+
+        pub fn bool_list_value(&mut self) -> ParquetResult<bool> {
+            Ok(self.next_byte()? != 0)
+        }
+    }
+}
+
+/// `ColumnIndex.boundary_order`: whether `min_values`/`max_values` are monotonic across pages,
+/// which lets [`narrow_filter`] stop scanning once it has moved past the matching pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryOrder {
+    Unordered,
+    Ascending,
+    Descending,
+}
+
+/// The decoded `ColumnIndex` Thrift struct.
+#[derive(Debug, Clone)]
+pub struct ColumnIndex {
+    pub null_pages: Vec<bool>,
+    pub min_values: Vec<Vec<u8>>,
+    pub max_values: Vec<Vec<u8>>,
+    pub boundary_order: BoundaryOrder,
+    pub null_counts: Option<Vec<i64>>,
+}
+
+/// One entry of the decoded `OffsetIndex.page_locations`.
+#[derive(Debug, Clone, Copy)]
+pub struct PageLocation {
+    pub offset: i64,
+    pub compressed_page_size: i32,
+    pub first_row_index: i64,
+}
+
+/// The decoded `OffsetIndex` Thrift struct.
+#[derive(Debug, Clone)]
+pub struct OffsetIndex {
+    pub page_locations: Vec<PageLocation>,
+}
+
+pub fn parse_column_index(data: &[u8]) -> ParquetResult<ColumnIndex> {
+    let mut r = thrift_compact::Reader::new(data);
+    let mut last_field_id = 0i16;
+
+    let mut null_pages = Vec::new();
+    let mut min_values = Vec::new();
+    let mut max_values = Vec::new();
+    let mut boundary_order = BoundaryOrder::Unordered;
+    let mut null_counts = None;
+
+    while let Some(type_code) = r.field_header(&mut last_field_id)? {
+        match (last_field_id, type_code) {
+            (1, 9) => {
+                let (_, size) = r.list_header()?;
+                null_pages = (0..size)
+                    .map(|_| r.bool_list_value())
+                    .collect::<ParquetResult<_>>()?;
+            },
+            (2, 9) => {
+                let (_, size) = r.list_header()?;
+                min_values = (0..size).map(|_| r.binary()).collect::<ParquetResult<_>>()?;
+            },
+            (3, 9) => {
+                let (_, size) = r.list_header()?;
+                max_values = (0..size).map(|_| r.binary()).collect::<ParquetResult<_>>()?;
+            },
+            (4, 5) => {
+                boundary_order = match r.i32()? {
+                    1 => BoundaryOrder::Ascending,
+                    2 => BoundaryOrder::Descending,
+                    _ => BoundaryOrder::Unordered,
+                };
+            },
+            (5, 9) => {
+                let (_, size) = r.list_header()?;
+                null_counts = Some((0..size).map(|_| r.i64()).collect::<ParquetResult<_>>()?);
+            },
+            (field_id, type_code) => {
+                return Err(ParquetError::not_supported(format!(
+                    "unexpected ColumnIndex field {field_id} (type {type_code})"
+                )));
+            },
+        }
+    }
+
+    Ok(ColumnIndex {
+        null_pages,
+        min_values,
+        max_values,
+        boundary_order,
+        null_counts,
+    })
+}
+
+pub fn parse_offset_index(data: &[u8]) -> ParquetResult<OffsetIndex> {
+    let mut r = thrift_compact::Reader::new(data);
+    let mut last_field_id = 0i16;
+    let mut page_locations = Vec::new();
+
+    while let Some(type_code) = r.field_header(&mut last_field_id)? {
+        match (last_field_id, type_code) {
+            (1, 9) => {
+                let (_, size) = r.list_header()?;
+                for _ in 0..size {
+                    page_locations.push(parse_page_location(&mut r)?);
+                }
+            },
+            (field_id, type_code) => {
+                return Err(ParquetError::not_supported(format!(
+                    "unexpected OffsetIndex field {field_id} (type {type_code})"
+                )));
+            },
+        }
+    }
+
+    Ok(OffsetIndex { page_locations })
+}
+
+fn parse_page_location(r: &mut thrift_compact::Reader) -> ParquetResult<PageLocation> {
+    let mut last_field_id = 0i16;
+    let mut offset = 0;
+    let mut compressed_page_size = 0;
+    let mut first_row_index = 0;
+
+    while let Some(type_code) = r.field_header(&mut last_field_id)? {
+        match (last_field_id, type_code) {
+            (1, 6) => offset = r.i64()?,
+            (2, 5) => compressed_page_size = r.i32()?,
+            (3, 6) => first_row_index = r.i64()?,
+            (field_id, type_code) => {
+                return Err(ParquetError::not_supported(format!(
+                    "unexpected PageLocation field {field_id} (type {type_code})"
+                )));
+            },
+        }
+    }
+
+    Ok(PageLocation {
+        offset,
+        compressed_page_size,
+        first_row_index,
+    })
+}
+
+/// A statistic value decoded from a `ColumnIndex` min/max entry, typed according to the
+/// column's physical and logical type (mirroring the `(physical_type, dtype)` match in
+/// [`super::simple::page_iter_to_array`]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatValue {
+    Boolean(bool),
+    Int32(i32),
+    Int64(i64),
+    Float32(f32),
+    Float64(f64),
+    Int128(i128),
+    Int256(i256),
+    Bytes(Vec<u8>),
+}
+
+/// Decode a single truncated min/max value out of a `ColumnIndex` entry, dispatching on
+/// `(physical_type, dtype)` the same way [`super::simple::page_iter_to_array`] dispatches page
+/// decoders: primitive, boolean, fixed-len-binary and byte-array layouts each get their own arm.
+///
+/// Returns `Ok(None)` when the spec allows the writer to have truncated this value in a way that
+/// makes it unsafe to use for page pruning here. The spec permits `ColumnIndex` min/max entries
+/// for variable-length binary/string logical types to be truncated, provided the writer keeps the
+/// *lexicographic* comparison conservative (truncate the min down, round the max up) -- so for the
+/// catch-all `StatValue::Bytes` case, byte-for-byte lexicographic comparison of the (possibly
+/// truncated) bytes stays safe and is used as-is. But `Decimal`/`Decimal256` stored as `ByteArray`
+/// are also variable-length and therefore just as truncatable, and there decoding the truncated
+/// bytes into a concrete `i128`/`i256` has no defined relationship to the true min/max: a shortened
+/// two's-complement value is not a lower/upper bound on the untruncated one. `FixedLenByteArray`
+/// Decimal storage has a schema-fixed width and isn't subject to this truncation, so it keeps
+/// decoding unconditionally.
+pub fn decode_stat_value(
+    physical_type: &PhysicalType,
+    dtype: &ArrowDataType,
+    bytes: &[u8],
+) -> ParquetResult<Option<StatValue>> {
+    use ArrowDataType::*;
+
+    Ok(Some(match (physical_type, dtype.to_logical_type()) {
+        (PhysicalType::Boolean, _) => StatValue::Boolean(bytes.first().copied().unwrap_or(0) != 0),
+        (PhysicalType::Int32, _) => StatValue::Int32(i32::from_le_bytes(
+            bytes
+                .try_into()
+                .map_err(|_| ParquetError::oos("invalid INT32 page statistic"))?,
+        )),
+        (PhysicalType::Int64, _) => StatValue::Int64(i64::from_le_bytes(
+            bytes
+                .try_into()
+                .map_err(|_| ParquetError::oos("invalid INT64 page statistic"))?,
+        )),
+        (PhysicalType::Int96, Timestamp(_, _)) => {
+            let bytes: [u8; 12] = bytes
+                .try_into()
+                .map_err(|_| ParquetError::oos("invalid INT96 page statistic"))?;
+            let value = [
+                u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+                u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+                u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+            ];
+            StatValue::Int64(int96_to_i64_ns(value))
+        },
+        (PhysicalType::Float, _) => StatValue::Float32(f32::from_le_bytes(
+            bytes
+                .try_into()
+                .map_err(|_| ParquetError::oos("invalid FLOAT page statistic"))?,
+        )),
+        (PhysicalType::Double, _) => StatValue::Float64(f64::from_le_bytes(
+            bytes
+                .try_into()
+                .map_err(|_| ParquetError::oos("invalid DOUBLE page statistic"))?,
+        )),
+        (PhysicalType::FixedLenByteArray(_), Decimal(_, _)) => {
+            if bytes.len() > 16 {
+                return Err(ParquetError::not_supported(format!(
+                    "can't decode Decimal128 page statistic from {} bytes",
+                    bytes.len()
+                )));
+            }
+            StatValue::Int128(convert_i128(bytes, bytes.len()))
+        },
+        (PhysicalType::FixedLenByteArray(_), Decimal256(_, _)) => {
+            if bytes.len() > 32 {
+                return Err(ParquetError::not_supported(format!(
+                    "can't decode Decimal256 page statistic from {} bytes",
+                    bytes.len()
+                )));
+            }
+            StatValue::Int256(convert_i256(bytes))
+        },
+        // `ByteArray`-stored Decimal/Decimal256 are variable-length and so may have been
+        // truncated by the writer; unlike the `Bytes` fallback, decoding the truncated bytes as a
+        // numeric value isn't safe, so don't use these statistics for pruning at all.
+        (PhysicalType::ByteArray, Decimal(_, _)) | (PhysicalType::ByteArray, Decimal256(_, _)) => {
+            return Ok(None);
+        },
+        _ => StatValue::Bytes(bytes.to_vec()),
+    }))
+}
+
+/// A predicate evaluated against a column's per-page statistics.
+pub trait StatisticsPredicate {
+    /// Returns `false` only when `[min, max]` is guaranteed to contain no matching value, i.e.
+    /// the page is safe to skip entirely.
+    fn could_match(&self, min: &StatValue, max: &StatValue) -> bool;
+
+    /// Whether a page made up entirely of nulls (`ColumnIndex.null_pages[i] == true`) could
+    /// still satisfy the predicate, e.g. an `is_null` check. Defaults to `false`.
+    fn admits_nulls(&self) -> bool {
+        false
+    }
+}
+
+/// Narrow `filter` (if given) to the rows covered by pages that `predicate` can't rule out,
+/// using the parsed `column_index`/`offset_index` for this column chunk.
+///
+/// Nested columns (`is_nested`) are skipped: a leaf-value page boundary doesn't line up with a
+/// top-level row boundary once repeated/optional ancestors are involved, so reconciling one
+/// against the other needs the `NestedState`'s definition/repetition levels, which this
+/// statistics-only pass doesn't have access to. Row-position filtering there still happens the
+/// same way it did before this module existed.
+pub fn narrow_filter(
+    column_index: &ColumnIndex,
+    offset_index: &OffsetIndex,
+    physical_type: &PhysicalType,
+    dtype: &ArrowDataType,
+    predicate: &dyn StatisticsPredicate,
+    num_rows: usize,
+    is_nested: bool,
+    existing: Option<Filter>,
+) -> ParquetResult<Option<Filter>> {
+    if is_nested {
+        return Ok(existing);
+    }
+
+    let num_pages = column_index.null_pages.len();
+    if num_pages != offset_index.page_locations.len()
+        || num_pages != column_index.min_values.len()
+        || num_pages != column_index.max_values.len()
+    {
+        return Err(ParquetError::oos(
+            "ColumnIndex/OffsetIndex page counts don't agree",
+        ));
+    }
+
+    let mut keep = MutableBitmap::from_len_zeroed(num_rows);
+    let mut seen_match = false;
+    for i in 0..num_pages {
+        let start = offset_index.page_locations[i].first_row_index as usize;
+        let end = offset_index
+            .page_locations
+            .get(i + 1)
+            .map(|next| next.first_row_index as usize)
+            .unwrap_or(num_rows);
+
+        let matches = if column_index.null_pages[i] {
+            predicate.admits_nulls()
+        } else {
+            let min = decode_stat_value(physical_type, dtype, &column_index.min_values[i])?;
+            let max = decode_stat_value(physical_type, dtype, &column_index.max_values[i])?;
+            // `None` means the stored min/max can't be trusted for pruning (e.g. a possibly
+            // writer-truncated ByteArray Decimal); never exclude a page on untrustworthy stats.
+            match (min, max) {
+                (Some(min), Some(max)) => predicate.could_match(&min, &max),
+                _ => true,
+            }
+        };
+
+        if matches {
+            seen_match = true;
+            for row in start..end {
+                keep.set(row, true);
+            }
+        } else if seen_match && column_index.boundary_order != BoundaryOrder::Unordered {
+            // min/max are monotonic across pages: once we've left the matching run we'll never
+            // re-enter it, so there's no point scanning the remaining pages.
+            break;
+        }
+    }
+
+    Ok(Some(intersect_with_mask(existing, keep.into())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A minimal test-only writer mirroring this module's thrift_compact reader, used to build inputs
+    // that exercise parse_column_index/parse_offset_index directly (mirroring the approach in
+    // delta_bitpacked.rs's tests).
+    fn write_uleb128(out: &mut Vec<u8>, mut value: u64) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value != 0 {
+                out.push(byte | 0x80);
+            } else {
+                out.push(byte);
+                break;
+            }
+        }
+    }
+
+    fn write_zigzag(out: &mut Vec<u8>, value: i64) {
+        let encoded = ((value << 1) ^ (value >> 63)) as u64;
+        write_uleb128(out, encoded);
+    }
+
+    // Always emits the "id follows as a zigzag varint" form (delta nibble 0), which keeps this
+    // encoder simple at the cost of a couple of extra bytes per field.
+    fn write_field_header(out: &mut Vec<u8>, field_id: i16, type_code: u8) {
+        out.push(type_code);
+        write_zigzag(out, field_id as i64);
+    }
+
+    fn write_list_header(out: &mut Vec<u8>, element_type: u8, size: usize) {
+        if size < 15 {
+            out.push(((size as u8) << 4) | element_type);
+        } else {
+            out.push(0xF0 | element_type);
+            write_uleb128(out, size as u64);
+        }
+    }
+
+    fn write_binary(out: &mut Vec<u8>, bytes: &[u8]) {
+        write_uleb128(out, bytes.len() as u64);
+        out.extend_from_slice(bytes);
+    }
+
+    fn encode_column_index(
+        null_pages: &[bool],
+        min_values: &[Vec<u8>],
+        max_values: &[Vec<u8>],
+        boundary_order: i32,
+    ) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_field_header(&mut out, 1, 9);
+        write_list_header(&mut out, 2, null_pages.len());
+        for &v in null_pages {
+            out.push(v as u8);
+        }
+        write_field_header(&mut out, 2, 9);
+        write_list_header(&mut out, 8, min_values.len());
+        for v in min_values {
+            write_binary(&mut out, v);
+        }
+        write_field_header(&mut out, 3, 9);
+        write_list_header(&mut out, 8, max_values.len());
+        for v in max_values {
+            write_binary(&mut out, v);
+        }
+        write_field_header(&mut out, 4, 5);
+        write_zigzag(&mut out, boundary_order as i64);
+        out.push(0); // stop field
+        out
+    }
+
+    fn encode_offset_index(page_locations: &[(i64, i32, i64)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_field_header(&mut out, 1, 9);
+        write_list_header(&mut out, 12, page_locations.len());
+        for &(offset, compressed_page_size, first_row_index) in page_locations {
+            write_field_header(&mut out, 1, 6);
+            write_zigzag(&mut out, offset);
+            write_field_header(&mut out, 2, 5);
+            write_zigzag(&mut out, compressed_page_size as i64);
+            write_field_header(&mut out, 3, 6);
+            write_zigzag(&mut out, first_row_index);
+            out.push(0); // stop field for this PageLocation
+        }
+        out.push(0); // stop field for OffsetIndex
+        out
+    }
+
+    #[test]
+    fn column_index_and_offset_index_roundtrip() {
+        let null_pages = vec![false, true];
+        let min_values = vec![vec![5u8], vec![]];
+        let max_values = vec![vec![9u8], vec![]];
+        let encoded = encode_column_index(&null_pages, &min_values, &max_values, 1);
+
+        let parsed = parse_column_index(&encoded).unwrap();
+        assert_eq!(parsed.null_pages, null_pages);
+        assert_eq!(parsed.min_values, min_values);
+        assert_eq!(parsed.max_values, max_values);
+        assert_eq!(parsed.boundary_order, BoundaryOrder::Ascending);
+
+        let page_locations = vec![(100i64, 50i32, 0i64), (150i64, 60i32, 2i64)];
+        let encoded = encode_offset_index(&page_locations);
+        let parsed = parse_offset_index(&encoded).unwrap();
+        assert_eq!(parsed.page_locations.len(), 2);
+        assert_eq!(parsed.page_locations[0].offset, 100);
+        assert_eq!(parsed.page_locations[0].first_row_index, 0);
+        assert_eq!(parsed.page_locations[1].first_row_index, 2);
+    }
+
+    struct MinAtLeastTen;
+    impl StatisticsPredicate for MinAtLeastTen {
+        fn could_match(&self, min: &StatValue, _max: &StatValue) -> bool {
+            match min {
+                StatValue::Bytes(bytes) => bytes.first().copied().unwrap_or(0) >= 10,
+                _ => true,
+            }
+        }
+    }
+
+    #[test]
+    fn narrow_filter_keeps_only_matching_pages() {
+        // Two pages over four rows; only the second page's stats satisfy MinAtLeastTen, so only
+        // rows 2-3 should survive.
+        let column_index = ColumnIndex {
+            null_pages: vec![false, false],
+            min_values: vec![vec![0u8], vec![10u8]],
+            max_values: vec![vec![5u8], vec![15u8]],
+            boundary_order: BoundaryOrder::Unordered,
+            null_counts: None,
+        };
+        let offset_index = OffsetIndex {
+            page_locations: vec![
+                PageLocation {
+                    offset: 0,
+                    compressed_page_size: 10,
+                    first_row_index: 0,
+                },
+                PageLocation {
+                    offset: 10,
+                    compressed_page_size: 10,
+                    first_row_index: 2,
+                },
+            ],
+        };
+
+        let filter = narrow_filter(
+            &column_index,
+            &offset_index,
+            &PhysicalType::ByteArray,
+            &ArrowDataType::Utf8View,
+            &MinAtLeastTen,
+            4,
+            false,
+            None,
+        )
+        .unwrap()
+        .unwrap();
+
+        let mask = match filter {
+            Filter::Mask(mask) => mask,
+            _ => panic!("expected a mask filter"),
+        };
+        assert_eq!(
+            (0..4).map(|i| mask.get_bit(i)).collect::<Vec<_>>(),
+            vec![false, false, true, true]
+        );
+    }
+}
+
+fn intersect_with_mask(existing: Option<Filter>, mask: Bitmap) -> Filter {
+    match existing {
+        None => Filter::Mask(mask),
+        Some(Filter::Mask(existing_mask)) => {
+            let mut combined = MutableBitmap::with_capacity(mask.len());
+            for i in 0..mask.len() {
+                combined.push(existing_mask.get_bit(i) && mask.get_bit(i));
+            }
+            Filter::Mask(combined.into())
+        },
+        // Any other `Filter` shape already encodes a more specific row selection than what we
+        // can losslessly intersect with a plain mask here; keep it as-is rather than risk
+        // widening the set of rows decoded.
+        Some(other) => other,
+    }
+}
@@ -1,4 +1,4 @@
-use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
+use arrow::array::{Array, BinaryViewArray, FixedSizeBinaryArray, PrimitiveArray};
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
@@ -8,6 +8,7 @@ use arrow::types::{NativeType, days_ms, i256};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 
+use super::indexes::{self, StatisticsPredicate};
 use super::utils::filter::Filter;
 use super::{
     BasicDecompressor, InitNested, NestedState, boolean, fixed_size_binary, null, primitive,
@@ -22,6 +23,23 @@ use crate::read::deserialize::binview;
 use crate::read::deserialize::categorical::CategoricalDecoder;
 use crate::read::deserialize::utils::PageDecoder;
 
+/// The raw `ColumnIndex`/`OffsetIndex` Thrift bytes for a column chunk, plus the predicate to
+/// evaluate their per-page statistics against and the chunk's total row count. Passed to
+/// [`page_iter_to_array`] to prune whole pages before they're decoded; see [`indexes`].
+///
+/// Unwired: nothing in this checkout constructs a `Some(PageIndexPushdown { .. })` value. The
+/// real call sites that own a row group's `ColumnChunkMetaData` (to read the Thrift
+/// `ColumnIndex`/`OffsetIndex` byte ranges off disk in the first place) and build a
+/// `StatisticsPredicate` from the user's filter expression aren't part of this checkout, so
+/// `page_iter_to_array` is only ever called here with `page_index: None`, i.e. page pruning is
+/// implemented but not yet reachable end-to-end.
+pub struct PageIndexPushdown<'a> {
+    pub column_index: &'a [u8],
+    pub offset_index: &'a [u8],
+    pub predicate: &'a dyn StatisticsPredicate,
+    pub num_rows: usize,
+}
+
 /// An iterator adapter that maps an iterator of Pages a boxed [`Array`] of [`ArrowDataType`]
 /// `dtype` with a maximum of `num_rows` elements.
 pub fn page_iter_to_array(
@@ -30,6 +48,7 @@ pub fn page_iter_to_array(
     field: Field,
     filter: Option<Filter>,
     init_nested: Option<Vec<InitNested>>,
+    page_index: Option<PageIndexPushdown>,
 ) -> ParquetResult<(Option<NestedState>, Box<dyn Array>, Bitmap)> {
     use ArrowDataType::*;
 
@@ -37,6 +56,29 @@ pub fn page_iter_to_array(
     let logical_type = &type_.logical_type;
     let dtype = field.dtype;
 
+    let filter = match page_index {
+        Some(PageIndexPushdown {
+            column_index,
+            offset_index,
+            predicate,
+            num_rows,
+        }) => {
+            let column_index = indexes::parse_column_index(column_index)?;
+            let offset_index = indexes::parse_offset_index(offset_index)?;
+            indexes::narrow_filter(
+                &column_index,
+                &offset_index,
+                physical_type,
+                &dtype,
+                predicate,
+                num_rows,
+                init_nested.is_some(),
+                filter,
+            )?
+        },
+        None => filter,
+    };
+
     Ok(match (physical_type, dtype.to_logical_type()) {
         (_, Null) => PageDecoder::new(&field.name, pages, dtype, null::NullDecoder, init_nested)?
             .collect_boxed(filter)?,
@@ -252,6 +294,39 @@ pub fn page_iter_to_array(
                 ptm,
             )
         },
+        (PhysicalType::ByteArray, Decimal(_, _)) => {
+            // @TODO: Make a separate decoder for this
+
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::BinaryView,
+                binview::BinViewDecoder { is_string: false },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            let values = array
+                .values_iter()
+                .map(|value: &[u8]| {
+                    if value.len() > 16 {
+                        return Err(ParquetError::not_supported(format!(
+                            "Can't decode Decimal value stored in {} bytes, max is 16",
+                            value.len()
+                        )));
+                    }
+                    Ok(super::super::convert_i128(value, value.len()))
+                })
+                .collect::<ParquetResult<Vec<_>>>()?;
+            let validity = array.validity().cloned();
+
+            (
+                nested,
+                PrimitiveArray::<i128>::try_new(dtype.clone(), values.into(), validity)?.to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::Int32, Decimal256(_, _)) => PageDecoder::new(
             &field.name,
             pages,
@@ -327,6 +402,37 @@ pub fn page_iter_to_array(
                 "Can't decode Decimal256 type from Fixed Size Byte Array of len {n:?}",
             )));
         },
+        (PhysicalType::ByteArray, Decimal256(_, _)) => {
+            let (nested, array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::BinaryView,
+                binview::BinViewDecoder { is_string: false },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            let values = array
+                .values_iter()
+                .map(|value: &[u8]| {
+                    if value.len() > 32 {
+                        return Err(ParquetError::not_supported(format!(
+                            "Can't decode Decimal256 value stored in {} bytes, max is 32",
+                            value.len()
+                        )));
+                    }
+                    Ok(super::super::convert_i256(value))
+                })
+                .collect::<ParquetResult<Vec<_>>>()?;
+            let validity = array.validity().cloned();
+
+            (
+                nested,
+                PrimitiveArray::<i256>::try_new(dtype.clone(), values.into(), validity)?.to_boxed(),
+                ptm,
+            )
+        },
         (PhysicalType::Int32, Date64) => PageDecoder::new(
             &field.name,
             pages,
@@ -362,30 +468,25 @@ pub fn page_iter_to_array(
         .collect_boxed(filter)?,
 
         // Float16
-        (PhysicalType::FixedLenByteArray(2), Float32) => {
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
 
-            let (nested, mut fsb_array, ptm) = PageDecoder::new(
-                &field.name,
-                pages,
-                ArrowDataType::FixedSizeBinary(2),
-                fixed_size_binary::BinaryDecoder { size: 2 },
-                init_nested,
-            )?
-            .collect(filter)?;
+            let (nested, values, validity, ptm) =
+                decode_fixed_len_f16(&field.name, pages, init_nested, filter)?;
 
-            let validity = fsb_array.take_validity();
-            let values = fsb_array.values().as_slice();
-            assert_eq!(values.len() % 2, 0);
-            let values = values.chunks_exact(2);
-            let values = values
-                .map(|v| {
-                    // SAFETY: We know that `v` is always of size two.
-                    let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
-                    let v = arrow::types::f16::from_le_bytes(le_bytes);
-                    v.to_f32()
-                })
-                .collect();
+            (
+                nested,
+                PrimitiveArray::<arrow::types::f16>::new(dtype, values, validity).to_boxed(),
+                ptm,
+            )
+        },
+        (PhysicalType::FixedLenByteArray(2), Float32) => {
+            // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder and widen the
+            // native Float16 payload to f32.
+
+            let (nested, f16_values, validity, ptm) =
+                decode_fixed_len_f16(&field.name, pages, init_nested, filter)?;
+            let values = f16_values.iter().map(|v| v.to_f32()).collect();
 
             (
                 nested,
@@ -502,6 +603,43 @@ pub fn page_iter_to_array(
     })
 }
 
+/// Decode a `FixedLenByteArray(2)` column into its native little-endian `f16` values, shared by
+/// the `Float16` and `Float32` (upcast) arms of [`page_iter_to_array`].
+fn decode_fixed_len_f16(
+    field_name: &str,
+    pages: BasicDecompressor,
+    init_nested: Option<Vec<InitNested>>,
+    filter: Option<Filter>,
+) -> ParquetResult<(
+    Option<NestedState>,
+    Vec<arrow::types::f16>,
+    Option<Bitmap>,
+    Bitmap,
+)> {
+    let (nested, mut fsb_array, ptm) = PageDecoder::new(
+        field_name,
+        pages,
+        ArrowDataType::FixedSizeBinary(2),
+        fixed_size_binary::BinaryDecoder { size: 2 },
+        init_nested,
+    )?
+    .collect(filter)?;
+
+    let validity = fsb_array.take_validity();
+    let values = fsb_array.values().as_slice();
+    assert_eq!(values.len() % 2, 0);
+    let values = values
+        .chunks_exact(2)
+        .map(|v| {
+            // SAFETY: We know that `v` is always of size two.
+            let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
+            arrow::types::f16::from_le_bytes(le_bytes)
+        })
+        .collect();
+
+    Ok((nested, values, validity, ptm))
+}
+
 /// Unify the timestamp unit from parquet TimeUnit into arrow's TimeUnit
 /// Returns (a int64 factor, is_multiplier)
 fn unify_timestamp_unit(
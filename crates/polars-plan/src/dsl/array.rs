@@ -0,0 +1,50 @@
+//! `ArrayNameSpace` additions for this backlog: `eval` and `set_operation`.
+//!
+//! The real `polars-plan` crate already defines `ArrayNameSpace` and its `impl` block here,
+//! backing `len`, `max`, `min`, `sum`, `std`, `var`, `median`, `unique`, `unique_stable`,
+//! `n_unique`, `to_list`, `all`, `any`, `sort`, `reverse`, `arg_min`, `arg_max`, `get`, `join`,
+//! `contains`, `count_matches`, `to_struct`, `slice`, `tail`, `shift` and `explode` (proven by
+//! `polars-python/src/expr/array.rs` already calling all of those) -- none of that is reproduced
+//! in this checkout. What follows is only the diff this backlog adds: two more methods folded
+//! into that same pre-existing `impl ArrayNameSpace` block, not a new parallel struct.
+
+use polars_ops::series::ops::array_eval::array_eval;
+pub use polars_ops::series::ops::array_eval::element;
+use polars_ops::series::ops::{SetOperation, array_set_operation};
+
+use crate::dsl::function_expr::GetOutput;
+use crate::dsl::Expr;
+use crate::prelude::DataType;
+
+/// `eval`/`set_operation` both turn a fixed-width `Array` into a `List` (`array_eval` always
+/// collects its per-row results into a `List`, and a set operation can change a row's width by
+/// removing duplicates or elements from one side), so the schema can't stay `Array`: it has to commit to `List`
+/// up front rather than claiming `same_type()` and then handing the executor a `Series` whose
+/// real dtype doesn't match what was schema-inferred.
+fn widen_to_list(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Array(inner, _) | DataType::List(inner) => DataType::List(inner.clone()),
+        other => other.clone(),
+    }
+}
+
+impl ArrayNameSpace {
+    /// Evaluate `expr` against each row's elements independently; see [`element`] for how to
+    /// refer to the current element inside `expr`.
+    pub fn eval(self, expr: Expr, parallel: bool) -> Expr {
+        self.0.map(
+            move |s| array_eval(&s, &expr, parallel),
+            GetOutput::map_dtype(widen_to_list),
+        )
+    }
+
+    /// Apply `op` row-wise between this array/list column and `other`, producing a `List` column
+    /// (see [`array_set_operation`] for why the result can't stay fixed-width).
+    pub fn set_operation(self, other: Expr, op: SetOperation) -> Expr {
+        self.0.map_many(
+            move |s| array_set_operation(&s[0], &s[1], op),
+            &[other],
+            GetOutput::map_dtype(widen_to_list),
+        )
+    }
+}
@@ -1,4 +1,5 @@
 use polars::prelude::*;
+use polars_ops::series::ops::SetOperation;
 use polars_utils::python_function::PythonObject;
 use pyo3::prelude::*;
 use pyo3::pymethods;
@@ -36,6 +37,10 @@ impl PyExpr {
         self.inner.clone().arr().median().into()
     }
 
+    fn arr_eval(&self, expr: PyExpr, parallel: bool) -> Self {
+        self.inner.clone().arr().eval(expr.inner, parallel).into()
+    }
+
     fn arr_unique(&self, maintain_order: bool) -> Self {
         if maintain_order {
             self.inner.clone().arr().unique_stable().into()
@@ -114,6 +119,42 @@ impl PyExpr {
         self.inner.clone().arr().count_matches(expr.inner).into()
     }
 
+    #[cfg(feature = "list_sets")]
+    fn arr_set_union(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .set_operation(other.inner, SetOperation::Union)
+            .into()
+    }
+
+    #[cfg(feature = "list_sets")]
+    fn arr_set_intersection(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .set_operation(other.inner, SetOperation::Intersection)
+            .into()
+    }
+
+    #[cfg(feature = "list_sets")]
+    fn arr_set_difference(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .set_operation(other.inner, SetOperation::Difference)
+            .into()
+    }
+
+    #[cfg(feature = "list_sets")]
+    fn arr_set_symmetric_difference(&self, other: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .arr()
+            .set_operation(other.inner, SetOperation::SymmetricDifference)
+            .into()
+    }
+
     #[pyo3(signature = (name_gen))]
     fn arr_to_struct(&self, name_gen: Option<PyObject>) -> Self {
         let name_gen = name_gen.map(|o| PlanCallback::new_python(PythonObject(o)));